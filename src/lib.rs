@@ -39,8 +39,22 @@ let my_value = config.getint("somesection", "someintvalue")?.unwrap();
 ## Supported `ini` file structure
 A configuration file can consist of sections, each led by a `[section-name]` header, followed by key-value entries separated by a `=`. By default, section names and key names are case-insensitive. All leading and trailing whitespace is removed from stored keys, values and section names.
 Key values can be omitted, in which case the key-value delimiter (`=`) may also be left out (but this is different from putting a delimiter, we'll
-explain it later). Key-value pairs or section headers cannot span multiple lines.
+explain it later). Key-value pairs or section headers cannot span multiple lines by default, but [`Parser::multiline`] turns on the indented-continuation
+mode Python's `configparser` has, by forwarding to `Ini::set_multiline`: after a `key = value` line, any line starting with whitespace that isn't a
+`[section]` header gets folded into the value (joined with a newline), and `Parser::write` re-indents continuation lines on the way back out.
 Owing to how ini files usually are, this means that `[`, `]` and `=` are special symbols (this crate will allow you to use `]` sparingly).
+[`Parser::escaping`] decodes backslash escapes in values once they come back from `Ini::read` — `\\`, `\n`, `\t`, `\0`, `\=`, `\;`, `\#`, and
+`\x` followed by exactly 6 hex digits for any other Unicode scalar — and [`Parser::write`] emits the inverse. The fixed 6-digit width on
+`\x` escapes matters: it's what lets decoding tell unambiguously where one escape ends and the next literal character begins. An escaped
+delimiter round-trips on read as long as it isn't the one `Ini` used to split the line in the first place — `key = val\=ue` is fine, but
+escaping the key/value separator itself (`key\=part = value`) still can't round-trip, since `Ini::read` has already split on the
+unescaped delimiter by the time `Parser` sees the value.
+
+`Ini` itself already recognizes `;`/`#` comment lines (and, by default, trailing inline comments too) — that's
+`Ini`'s own built-in behavior, not something layered on afterwards. [`Parser::comment_symbols`] and
+[`Parser::inline_comments`] just forward your choice of symbols to a fresh `Ini` configured via
+`configparser::ini::IniDefault`, with one deliberate difference from `Ini`'s own default: inline comments are
+opt-in here, since values legitimately contain `#`/`;`. See [`Parser`]'s docs for the builder methods.
 
 Let's take for example:
 ```INI
@@ -64,15 +78,25 @@ integers, floats and booleans are held as= strings
 a_valueless_key_has_None
 this key has an empty string value has Some("") =
 
-	[indented sections]
-		can_values_be_as_well = True
-		purpose = formatting for readability
-		is_this_same     =        yes
-			is_this_same=yes
+    [indented sections]
+        can_values_be_as_well = True
+        purpose = formatting for readability
+        is_this_same     =        yes
+            is_this_same=yes
 ```
 An important thing to note is that values with the same keys will get updated, this means that the last inserted key (whether that's a section header
 or property key) is the one that remains in the `HashMap`.
-The only bit of magic the API does is the section-less properties are put in a section called "default". It is planned to allow configuring this variable.
+The only bit of magic the API does is the section-less properties are put in a section called "default". `Ini` itself calls it that unless told otherwise, and
+[`Parser::default_section_name`] forwards whatever name you configure to `Ini::set_default_section` on both load and [`Parser::write`], so
+callers that want `"DEFAULT"` or another convention don't have to live with the literal string "default".
+
+Because sections and keys are stored in a `HashMap`, `load`-ing a file and `write`-ing it back out does not preserve the
+original ordering of either — by the time a map comes back from `Ini::read`, that ordering is already gone, so there's
+nothing to recover from the map itself. [`Parser::ordered`] works around this with a shadow scan: alongside the real
+parse, it walks the same text separately to record first-insertion order, exposed via [`Parser::sections`] and
+[`Parser::get_keys`]. The map handed back from [`Parser::read`]/[`Parser::load`] stays a plain unordered `HashMap`
+either way, but [`Parser::write`] uses that recorded order to sequence its output, so a load followed by a write
+reproduces the original layout rather than whatever order the `HashMap` happens to iterate in.
 
 ## Usage
 Let's take another simple `ini` file and talk about working with it:
@@ -126,9 +150,102 @@ fn main() -> Result<(), Box<dyn Error>> {
   Ok(())
 }
 ```
+
+## The `ini!` macro
+Loading a file and immediately wanting the resulting map (or a mutable parser to keep working with) is such
+a common pattern that this crate also ships an `ini!` macro shortcut:
+```ignore,rust
+use configparser::ini::Ini;
+
+// Just want the map? Load a file straight into one:
+let map = ini!("tests/test.ini");
+
+// Or parse from a string you already have in memory:
+let map = ini!(str = "[topsecret]\nkfc = the secret herb is orega-\n");
+
+// Need to keep mutating the parser afterwards (write it back out, use the
+// typed getters, etc.)? Bind a mutable `Ini` instead of just getting the map:
+ini!(mut config = "tests/test.ini");
+let secret = config.get("topsecret", "kfc");
+```
+All three forms go through `Ini::load`/`Ini::read` under the hood, so the same case-insensitive
+lowercasing of section and key names applies. A load failure panics rather than returning a `Result`,
+since the whole point of the macro is to skip the error-handling boilerplate for the common case.
+
+## Fluent section builder
+Building a config programmatically the way `get_mut_map()` wants means reaching in and inserting
+`String`/`Option<String>` pairs into nested `HashMap`s by hand. [`IniSectionExt::with_section`] gives a
+chainable write-path instead:
+```ignore,rust
+use configparser::ini::Ini;
+use ini_rs::IniSectionExt;
+
+let mut config = Ini::new();
+config.with_section(Some("User")).set("given_name", "Tommy").set("family_name", "Green");
+config.with_section(None).set("encoding", "utf-8");
+```
+
+## Opt-in preprocessing with `Parser`
+[`Parser`] is a thin wrapper that sits in front of `Ini::read`/`Ini::load`, transforming the raw text (and,
+where noted, the returned map) to support modes `Ini` itself doesn't know about. Every mode defaults to off,
+so `Parser::new().read(..)` behaves like `Ini::new().read(..)` except for inline comments, which `Ini` strips
+by default and `Parser` leaves in the value unless [`Parser::inline_comments`] is enabled.
+```ignore,rust
+use ini_rs::Parser;
+
+let map = Parser::new().comment_symbols(&[';', '#']).load("tests/test.ini").unwrap();
+```
 */
 pub use configparser;
 
+mod parser;
+pub use parser::Parser;
+
+mod section;
+pub use section::{IniSectionExt, SectionHandle};
+
+/// See the [crate-level docs](index.html#the-ini-macro) for usage examples.
+#[macro_export]
 macro_rules! ini {
-	{$path:literal}
+	($path:literal) => {{
+		let mut __ini = $crate::configparser::ini::Ini::new();
+		__ini.load($path).unwrap()
+	}};
+	(str = $contents:expr) => {{
+		let mut __ini = $crate::configparser::ini::Ini::new();
+		__ini.read($contents.to_string()).unwrap()
+	}};
+	(mut $name:ident = $path:literal) => {
+		let mut $name = $crate::configparser::ini::Ini::new();
+		$name.load($path).unwrap();
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn path_form_loads_the_map() {
+		let map = ini!("tests/test.ini");
+		assert_eq!(map["topsecret"]["kfc"], Some("the secret herb is orega-".to_string()));
+	}
+
+	#[test]
+	fn str_form_parses_an_in_memory_string() {
+		let map = ini!(str = "[topsecret]\nkfc = the secret herb is orega-\n");
+		assert_eq!(map["topsecret"]["kfc"], Some("the secret herb is orega-".to_string()));
+	}
+
+	#[test]
+	fn mut_form_binds_a_usable_parser() {
+		ini!(mut config = "tests/test.ini");
+		assert_eq!(config.get("topsecret", "kfc"), Some("the secret herb is orega-".to_string()));
+		config.get_mut_map().get_mut("topsecret").unwrap().insert("kfc".to_string(), None);
+		assert_eq!(config.get("topsecret", "kfc"), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn path_form_panics_on_a_missing_file() {
+		ini!("tests/does-not-exist.ini");
+	}
 }
\ No newline at end of file
@@ -0,0 +1,606 @@
+use configparser::ini::{Ini, IniDefault};
+use std::collections::HashMap;
+use std::fs;
+
+type Map = HashMap<String, HashMap<String, Option<String>>>;
+
+/// A small pre/post-processing wrapper around [`Ini`] that adds opt-in parsing modes on top of
+/// it. `configparser::ini::Ini` itself does the actual tokenizing; most of `Parser`'s modes
+/// either configure a fresh `Ini` via `configparser::ini::IniDefault` (comments, the default
+/// section name) or transform the raw text handed to `Ini::read`/`Ini::load`, or the map handed
+/// back, for modes `Ini` doesn't expose a knob for.
+///
+/// ```ignore,rust
+/// use ini_rs::Parser;
+///
+/// let map = Parser::new()
+///     .comment_symbols(&[';', '#'])
+///     .inline_comments(true)
+///     .read("; a full-line comment\nkey = value # an inline one\n")
+///     .unwrap();
+/// assert_eq!(map["default"]["key"], Some("value".to_string()));
+/// ```
+pub struct Parser {
+	comment_symbols: Vec<char>,
+	inline_comments: bool,
+	escaping: bool,
+	default_section_name: String,
+	ordered: bool,
+	multiline: bool,
+	section_order: Vec<String>,
+	key_order: HashMap<String, Vec<String>>,
+}
+
+impl Parser {
+	/// Creates a `Parser` with every opt-in mode disabled, matching `Ini`'s own default behavior
+	/// except that inline comments are off (see [`Parser::inline_comments`]).
+	pub fn new() -> Self {
+		Parser {
+			comment_symbols: vec![';', '#'],
+			inline_comments: false,
+			escaping: false,
+			default_section_name: "default".to_string(),
+			ordered: false,
+			multiline: false,
+			section_order: Vec::new(),
+			key_order: HashMap::new(),
+		}
+	}
+
+	/// Lines whose first non-whitespace character is one of `symbols` are skipped entirely
+	/// during parsing and never create a map entry. `Ini` already has this built in
+	/// (`Ini::set_comment_symbols`, defaulting to `;`/`#`); `Parser` just forwards the
+	/// configured symbols to a fresh `Ini` via [`IniDefault`] instead of reimplementing
+	/// comment stripping itself. Defaults to `;` and `#`, matching `Ini`'s own default and
+	/// common ini practice.
+	pub fn comment_symbols(mut self, symbols: &[char]) -> Self {
+		self.comment_symbols = symbols.to_vec();
+		self
+	}
+
+	/// When enabled, a comment symbol preceded by whitespace elsewhere on a line strips the
+	/// rest of that line before parsing (e.g. `key = value ; trailing note`), using `Ini`'s
+	/// own inline-comment support. Off by default — unlike `Ini`'s own default of on — since
+	/// values legitimately contain `#`/`;`.
+	pub fn inline_comments(mut self, enabled: bool) -> Self {
+		self.inline_comments = enabled;
+		self
+	}
+
+	/// Decodes backslash escapes in values after parsing: `\\`, `\n`, `\t`, `\0`, `\=`, `\;`, `\#`,
+	/// and `\x` followed by exactly 6 hex digits for any other Unicode scalar value. On write
+	/// ([`Parser::write`]), the inverse is applied: those same characters are escaped, and any
+	/// non-printable-ASCII scalar value is encoded as `\x` plus 6 hex digits. Off by default to
+	/// preserve today's literal behavior.
+	///
+	/// This only covers escapes inside an already-tokenized value — a value containing `\=`,
+	/// `\;` or `\#` decodes correctly as long as `Ini` didn't treat the escaped character as the
+	/// delimiter it was splitting on in the first place (e.g. the `=` in `key = val\=ue` isn't
+	/// ambiguous, since it's not the first `=` on the line). An escape sitting exactly where
+	/// `Ini` looks for its delimiter — e.g. escaping the key/value separator itself, as in
+	/// `key\=part = value` — still can't round-trip, since `Ini::read` has already split the
+	/// line by the time this decoding runs.
+	pub fn escaping(mut self, enabled: bool) -> Self {
+		self.escaping = enabled;
+		self
+	}
+
+	/// Changes which section top-of-file keys (the ones preceding any `[header]`) end up under.
+	/// `Ini` already has a knob for this (`Ini::set_default_section`); `Parser` just forwards the
+	/// configured name to it on both [`Parser::read`]/[`Parser::load`] (via
+	/// `configparser::ini::IniDefault`) and [`Parser::write`], instead of renaming a hardcoded
+	/// `"default"` section back and forth. Note that, matching `Ini`'s own behavior, the name is
+	/// used verbatim (not lowercased) — pass `"DEFAULT"` if that's the casing your format expects.
+	/// Defaults to `"default"`, matching `Ini`'s own behavior untouched.
+	pub fn default_section_name(mut self, name: &str) -> Self {
+		self.default_section_name = name.to_string();
+		self
+	}
+
+	/// `Ini`'s own storage is a `HashMap`, so `load`-ing a file and `write`-ing it back out does
+	/// not preserve the original ordering of sections or keys. Since that `HashMap` lives inside
+	/// `configparser` and can't be swapped out from here, `Parser` instead does a shadow scan of
+	/// the raw text alongside the real parse to record first-insertion order, exposed via
+	/// [`Parser::sections`] and [`Parser::get_keys`] once [`Parser::read`]/[`Parser::load`] has
+	/// run. Off by default; the map itself is always the real, unordered `Ini` output either way.
+	pub fn ordered(mut self, enabled: bool) -> Self {
+		self.ordered = enabled;
+		self
+	}
+
+	/// After a `key = value` line, any subsequent line starting with whitespace is folded into
+	/// the previous value (joined with a newline), while a `[section]` header always starts a
+	/// new section even if indented. `Ini` already implements this (`Ini::set_multiline`,
+	/// including the matching write-side re-indentation via `WriteOptions`); `Parser` forwards
+	/// the setting to `Ini` on both [`Parser::read`]/[`Parser::load`] and [`Parser::write`]
+	/// rather than folding/restoring continuation lines itself. Off by default, which preserves
+	/// today's one-line-per-key behavior.
+	pub fn multiline(mut self, enabled: bool) -> Self {
+		self.multiline = enabled;
+		self
+	}
+
+	/// Parses `input` with the configured modes applied, using a fresh [`Ini`] underneath.
+	pub fn read(&mut self, input: &str) -> Result<Map, String> {
+		let mut defaults = IniDefault::default();
+		defaults.comment_symbols = self.comment_symbols.clone();
+		defaults.enable_inline_comments = self.inline_comments;
+		defaults.multiline = self.multiline;
+		defaults.default_section = self.default_section_name.clone();
+		let mut map = Ini::new_from_defaults(defaults).read(input.to_string())?;
+		if self.escaping {
+			unescape_map(&mut map)?;
+		}
+		if self.ordered {
+			let (sections, keys) = scan_order(input, &self.default_section_name.to_lowercase(), &self.comment_symbols, self.multiline);
+			self.section_order = sections;
+			self.key_order = keys;
+		} else {
+			self.section_order.clear();
+			self.key_order.clear();
+		}
+		Ok(map)
+	}
+
+	/// Reads `path` from disk and parses it the same way as [`Parser::read`].
+	pub fn load(&mut self, path: &str) -> Result<Map, String> {
+		let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+		self.read(&contents)
+	}
+
+	/// Sections in the order they first appeared in the text last passed to
+	/// [`Parser::read`]/[`Parser::load`]. Empty unless [`Parser::ordered`] was enabled.
+	pub fn sections(&self) -> &[String] {
+		&self.section_order
+	}
+
+	/// Keys within `section` (matched case-insensitively) in the order they first appeared.
+	/// `None` if no keys were recorded for the section (it is unknown, or listed in
+	/// [`Parser::sections`] but has no keys) or [`Parser::ordered`] wasn't enabled.
+	pub fn get_keys(&self, section: &str) -> Option<&[String]> {
+		self.key_order.get(&section.to_lowercase()).map(Vec::as_slice)
+	}
+
+	/// Writes `ini` to `path`, first applying [`Parser::default_section_name`] and
+	/// [`Parser::multiline`] to `ini` via `Ini::set_default_section`/`Ini::set_multiline` so the
+	/// file comes out the way those settings describe, and, if [`Parser::escaping`] is enabled,
+	/// temporarily escaping every value so the file written is the inverse of what
+	/// [`Parser::read`] would decode — `ini`'s own map is left exactly as it was afterwards.
+	///
+	/// If [`Parser::ordered`] is enabled, the file is serialized directly from
+	/// [`Parser::sections`]/[`Parser::get_keys`] instead of going through `Ini::write`, so a
+	/// `load` followed by `write` reproduces the original section/key layout rather than
+	/// whatever order `ini`'s underlying `HashMap` happens to iterate in. Sections or keys added
+	/// to `ini` after the last [`Parser::read`]/[`Parser::load`] (so they have no recorded order)
+	/// are appended at the end, in `HashMap` order.
+	pub fn write(&self, ini: &mut Ini, path: &str) -> Result<(), String> {
+		ini.set_default_section(&self.default_section_name);
+		ini.set_multiline(self.multiline);
+		let original = self.escaping.then(|| {
+			let original = ini.get_mut_map().clone();
+			escape_map_values(ini.get_mut_map());
+			original
+		});
+		let result = if self.ordered {
+			let contents = unparse_ordered(
+				ini.get_map_ref(),
+				&self.default_section_name,
+				&self.section_order,
+				&self.key_order,
+				self.multiline,
+			);
+			fs::write(path, contents).map_err(|err| err.to_string())
+		} else {
+			ini.write(path).map_err(|err| err.to_string())
+		};
+		if let Some(original) = original {
+			*ini.get_mut_map() = original;
+		}
+		result
+	}
+}
+
+impl Default for Parser {
+	fn default() -> Self {
+		Parser::new()
+	}
+}
+
+/// Walks the raw text recording the order sections and keys first appear in, independent of
+/// (and blind to) whatever order the real `HashMap`-backed parse ends up storing them in. Lines
+/// that `Ini` itself would treat as full-line comments (first non-whitespace character is one of
+/// `comment_symbols`) are skipped, the same way `Ini`'s own parse skips them, so they never show
+/// up as phantom keys here. When `multiline` is set, lines indented under a preceding entry are
+/// `Ini::set_multiline` continuation lines rather than new keys, and are skipped rather than
+/// misread as one.
+fn scan_order(input: &str, default_section_name: &str, comment_symbols: &[char], multiline: bool) -> (Vec<String>, HashMap<String, Vec<String>>) {
+	let mut section_order = Vec::new();
+	let mut key_order: HashMap<String, Vec<String>> = HashMap::new();
+	let mut current = default_section_name.to_string();
+
+	let note_section = |name: &str, section_order: &mut Vec<String>| {
+		if !section_order.iter().any(|s| s == name) {
+			section_order.push(name.to_string());
+		}
+	};
+	note_section(&current, &mut section_order);
+
+	for raw_line in input.lines() {
+		let line = raw_line.trim();
+		if line.is_empty() {
+			continue;
+		}
+		if line.starts_with(|c: char| comment_symbols.contains(&c)) {
+			continue;
+		}
+		if line.starts_with('[') {
+			if let Some(end) = line.find(']') {
+				current = line[1..end].trim().to_lowercase();
+				note_section(&current, &mut section_order);
+			}
+			continue;
+		}
+		if multiline && raw_line.starts_with(char::is_whitespace) {
+			continue;
+		}
+		let key = line.split('=').next().unwrap_or(line).trim().to_lowercase();
+		if key.is_empty() {
+			continue;
+		}
+		let keys = key_order.entry(current.clone()).or_default();
+		if !keys.iter().any(|k| k == &key) {
+			keys.push(key);
+		}
+	}
+	(section_order, key_order)
+}
+
+/// Serializes `map` into `ini`-syntax text sequenced by `section_order`/`key_order`, matching
+/// `Ini`'s own default formatting (`WriteOptions::default()`: no spaces around `=`, continuation
+/// lines indented 4 spaces when `multiline`, no blank line between sections). `section_order`'s
+/// default-section entry is recorded lowercased (see [`scan_order`]), but `default_section_name`
+/// is looked up in `map` verbatim, matching how `Ini` itself stores it. Any section or key
+/// present in `map` but not in the recorded order (added to `ini` after the last
+/// [`Parser::read`]/[`Parser::load`]) is appended at the end, in `HashMap` iteration order.
+fn unparse_ordered(
+	map: &Map,
+	default_section_name: &str,
+	section_order: &[String],
+	key_order: &HashMap<String, Vec<String>>,
+	multiline: bool,
+) -> String {
+	let default_key_order_name = default_section_name.to_lowercase();
+	let mut out = String::new();
+	if let Some(section_map) = map.get(default_section_name) {
+		write_section(&mut out, section_map, key_order.get(&default_key_order_name).map(Vec::as_slice), multiline);
+	}
+
+	let mut written_sections: Vec<&str> = vec![default_section_name];
+	for section in section_order {
+		if *section == default_key_order_name {
+			continue;
+		}
+		if let Some(section_map) = map.get(section) {
+			out.push('[');
+			out.push_str(section);
+			out.push_str("]\n");
+			write_section(&mut out, section_map, key_order.get(section).map(Vec::as_slice), multiline);
+			written_sections.push(section.as_str());
+		}
+	}
+	for (section, section_map) in map {
+		if !written_sections.contains(&section.as_str()) {
+			out.push('[');
+			out.push_str(section);
+			out.push_str("]\n");
+			write_section(&mut out, section_map, key_order.get(section).map(Vec::as_slice), multiline);
+		}
+	}
+	out
+}
+
+/// Writes `section_map`'s `key = value` lines in `keys`' order (if given), followed by any keys
+/// `keys` doesn't mention, in `HashMap` iteration order.
+fn write_section(out: &mut String, section_map: &HashMap<String, Option<String>>, keys: Option<&[String]>, multiline: bool) {
+	let mut written: Vec<&str> = Vec::new();
+	if let Some(keys) = keys {
+		for key in keys {
+			if let Some(value) = section_map.get(key) {
+				write_key_value(out, key, value, multiline);
+				written.push(key.as_str());
+			}
+		}
+	}
+	for (key, value) in section_map {
+		if !written.contains(&key.as_str()) {
+			write_key_value(out, key, value, multiline);
+		}
+	}
+}
+
+/// Writes a single `key = value` (or bare `key`) line, re-indenting multiline continuation lines
+/// by 4 spaces the way `Ini`'s own default `WriteOptions` does.
+fn write_key_value(out: &mut String, key: &str, value: &Option<String>, multiline: bool) {
+	out.push_str(key);
+	if let Some(value) = value {
+		out.push('=');
+		if multiline {
+			let mut lines = value.lines();
+			out.push_str(lines.next().unwrap_or_default());
+			for line in lines {
+				out.push('\n');
+				if !line.is_empty() {
+					out.push_str("    ");
+					out.push_str(line);
+				}
+			}
+		} else {
+			out.push_str(value);
+		}
+	}
+	out.push('\n');
+}
+
+/// The inverse of [`unescape_map`]/[`unescape_value`], applied to every value in `map` in place
+/// before [`Parser::write`] hands it to `Ini::write`.
+fn escape_map_values(map: &mut Map) {
+	for section in map.values_mut() {
+		for v in section.values_mut().flatten() {
+			*v = escape_value(v);
+		}
+	}
+}
+
+/// Escapes `\`, the `=`/`;`/`#` delimiter and comment characters, and the control characters
+/// [`unescape_value`] decodes, then encodes any remaining non-printable-ASCII scalar value as
+/// `\x` followed by exactly 6 hex digits (zero-padded), so it round-trips through a plain-ASCII
+/// ini file. The width is fixed rather than minimal so [`unescape_value`] can always tell where
+/// one escape ends and the next literal character begins, instead of guessing.
+fn escape_value(value: &str) -> String {
+	let mut out = String::with_capacity(value.len());
+	for c in value.chars() {
+		match c {
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\t' => out.push_str("\\t"),
+			'\0' => out.push_str("\\0"),
+			'=' | ';' | '#' => {
+				out.push('\\');
+				out.push(c);
+			}
+			c if c.is_ascii() && !c.is_ascii_control() => out.push(c),
+			c => out.push_str(&format!("\\x{:06x}", c as u32)),
+		}
+	}
+	out
+}
+
+fn unescape_map(map: &mut Map) -> Result<(), String> {
+	for section in map.values_mut() {
+		for v in section.values_mut().flatten() {
+			*v = unescape_value(v)?;
+		}
+	}
+	Ok(())
+}
+
+fn unescape_value(value: &str) -> Result<String, String> {
+	let mut out = String::with_capacity(value.len());
+	let mut chars = value.chars().peekable();
+	while let Some(c) = chars.next() {
+		if c != '\\' {
+			out.push(c);
+			continue;
+		}
+		match chars.next() {
+			Some('\\') => out.push('\\'),
+			Some('n') => out.push('\n'),
+			Some('t') => out.push('\t'),
+			Some('0') => out.push('\0'),
+			Some('=') => out.push('='),
+			Some(';') => out.push(';'),
+			Some('#') => out.push('#'),
+			Some('x') => {
+				let hex: String = (&mut chars).take(6).collect();
+				if hex.len() < 6 || !hex.chars().all(|d| d.is_ascii_hexdigit()) {
+					return Err(format!("incomplete \\x escape in value: {:?}", value));
+				}
+				let code = u32::from_str_radix(&hex, 16)
+					.map_err(|_| format!("invalid \\x escape in value: {:?}", value))?;
+				let decoded = char::from_u32(code)
+					.ok_or_else(|| format!("\\x{:x} is not a valid Unicode scalar value", code))?;
+				out.push(decoded);
+			}
+			Some(other) => {
+				out.push('\\');
+				out.push(other);
+			}
+			None => out.push('\\'),
+		}
+	}
+	Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn full_line_comments_are_skipped_by_default() {
+		let map = Parser::new().read("; a full-line comment\n# another one\nkey = value\n").unwrap();
+		assert_eq!(map["default"]["key"], Some("value".to_string()));
+		assert_eq!(map["default"].len(), 1);
+	}
+
+	#[test]
+	fn inline_comments_are_off_by_default() {
+		let map = Parser::new().read("key = value # not a comment by default\n").unwrap();
+		assert_eq!(map["default"]["key"], Some("value # not a comment by default".to_string()));
+	}
+
+	#[test]
+	fn inline_comments_can_be_opted_into() {
+		let map = Parser::new().inline_comments(true).read("key = value # trailing note\n").unwrap();
+		assert_eq!(map["default"]["key"], Some("value".to_string()));
+	}
+
+	#[test]
+	fn custom_comment_symbols_replace_the_defaults() {
+		let map = Parser::new().comment_symbols(&['!']).read("# not a comment anymore\nkey = value\n").unwrap();
+		assert_eq!(map["default"]["# not a comment anymore"], None);
+		assert_eq!(map["default"]["key"], Some("value".to_string()));
+	}
+
+	#[test]
+	fn scan_order_skips_comment_lines_so_they_are_not_treated_as_keys() {
+		let mut parser = Parser::new().ordered(true);
+		parser.read("; a full-line comment\nkey = value\n").unwrap();
+		assert_eq!(parser.get_keys("default"), Some(["key".to_string()].as_slice()));
+	}
+
+	#[test]
+	fn scan_order_honors_custom_comment_symbols() {
+		let mut parser = Parser::new().comment_symbols(&['!']).ordered(true);
+		parser.read("# not a comment anymore\nkey = value\n").unwrap();
+		assert_eq!(parser.get_keys("default"), Some(["# not a comment anymore".to_string(), "key".to_string()].as_slice()));
+	}
+
+	#[test]
+	fn ordered_write_reproduces_the_original_section_and_key_layout() {
+		let mut parser = Parser::new().ordered(true);
+		let input = "[zzz]\nb=1\na=2\n[aaa]\nd=3\nc=4\n";
+		let mut map = parser.read(input).unwrap();
+		assert_eq!(parser.sections(), ["default", "zzz", "aaa"]);
+		assert_eq!(parser.get_keys("zzz"), Some(["b".to_string(), "a".to_string()].as_slice()));
+
+		let mut ini = Ini::new();
+		*ini.get_mut_map() = std::mem::take(&mut map);
+		let path = std::env::temp_dir().join("ini_rs_ordered_write_reproduces_layout.ini");
+		parser.write(&mut ini, path.to_str().unwrap()).unwrap();
+		let contents = fs::read_to_string(&path).unwrap();
+		fs::remove_file(&path).ok();
+
+		let zzz_pos = contents.find("[zzz]").unwrap();
+		let aaa_pos = contents.find("[aaa]").unwrap();
+		assert!(zzz_pos < aaa_pos, "expected [zzz] before [aaa], got: {:?}", contents);
+		let b_pos = contents.find("b=1").unwrap();
+		let a_pos = contents.find("a=2").unwrap();
+		assert!(b_pos < a_pos, "expected b before a within [zzz], got: {:?}", contents);
+		let d_pos = contents.find("d=3").unwrap();
+		let c_pos = contents.find("c=4").unwrap();
+		assert!(d_pos < c_pos, "expected d before c within [aaa], got: {:?}", contents);
+	}
+
+	#[test]
+	fn ordered_accessors_are_empty_until_ordered_is_enabled() {
+		let mut parser = Parser::new();
+		parser.read("[A]\nk=1\n").unwrap();
+		assert!(parser.sections().is_empty());
+		assert!(parser.get_keys("a").is_none());
+	}
+
+	#[test]
+	fn disabling_ordered_clears_stale_order_from_a_previous_parse() {
+		let mut parser = Parser::new().ordered(true);
+		parser.read("[A]\nk=1\n[B]\nj=2\n").unwrap();
+		assert_eq!(parser.sections(), ["default", "a", "b"]);
+
+		let mut parser = parser.ordered(false);
+		parser.read("[Z]\nz=1\n").unwrap();
+		assert!(parser.sections().is_empty());
+		assert!(parser.get_keys("a").is_none());
+	}
+
+	#[test]
+	fn multiline_folds_indented_continuation_lines() {
+		let map = Parser::new().multiline(true).read("key = first\n    second\n").unwrap();
+		assert_eq!(map["default"]["key"], Some("first\nsecond".to_string()));
+	}
+
+	#[test]
+	fn multiline_does_not_corrupt_values_with_control_characters() {
+		let map = Parser::new().multiline(true).read("key = before\u{1}after\n").unwrap();
+		assert_eq!(map["default"]["key"], Some("before\u{1}after".to_string()));
+	}
+
+	#[test]
+	fn multiline_round_trips_through_write() {
+		let mut ini = Ini::new();
+		ini.get_mut_map().entry("default".to_string()).or_default().insert("key".to_string(), Some("first\nsecond".to_string()));
+		let path = std::env::temp_dir().join("ini_rs_multiline_round_trips_through_write.ini");
+		Parser::new().multiline(true).write(&mut ini, path.to_str().unwrap()).unwrap();
+		let contents = fs::read_to_string(&path).unwrap();
+		fs::remove_file(&path).ok();
+		assert!(contents.contains("\n    second"), "expected an indented continuation line, got: {:?}", contents);
+		let map = Parser::new().multiline(true).read(&contents).unwrap();
+		assert_eq!(map["default"]["key"], Some("first\nsecond".to_string()));
+	}
+
+	#[test]
+	fn escape_value_escapes_delimiters_control_characters_and_non_ascii() {
+		assert_eq!(escape_value("a=b;c#d\\e\nf\tg\0h"), "a\\=b\\;c\\#d\\\\e\\nf\\tg\\0h");
+		assert_eq!(escape_value("caf\u{e9}"), "caf\\x0000e9");
+	}
+
+	#[test]
+	fn escape_value_always_pads_hex_escapes_to_six_digits_so_decoding_is_unambiguous() {
+		// A naive minimum-width hex escape would make "caf\x00e9" followed by literal "ab"
+		// indistinguishable from a wider escape; the fixed 6-digit width rules that out.
+		let escaped = format!("{}ab", escape_value("caf\u{e9}"));
+		assert_eq!(unescape_value(&escaped).unwrap(), "caf\u{e9}ab");
+	}
+
+	#[test]
+	fn unescape_value_decodes_escaped_delimiter_and_comment_characters() {
+		assert_eq!(unescape_value("val\\=ue").unwrap(), "val=ue".to_string());
+		assert_eq!(unescape_value("val\\;ue").unwrap(), "val;ue".to_string());
+		assert_eq!(unescape_value("val\\#ue").unwrap(), "val#ue".to_string());
+	}
+
+	#[test]
+	fn escaped_delimiter_round_trips_through_read_when_it_is_not_the_real_delimiter() {
+		let map = Parser::new().escaping(true).read("key = val\\=ue\n").unwrap();
+		assert_eq!(map["default"]["key"], Some("val=ue".to_string()));
+	}
+
+	#[test]
+	fn escaping_round_trips_backslash_and_control_escapes_through_write_and_read() {
+		let value = "back\\slash\ttab\nnewline\0nul caf\u{e9}";
+		let mut ini = Ini::new();
+		ini.get_mut_map().entry("default".to_string()).or_default().insert("key".to_string(), Some(value.to_string()));
+		let path = std::env::temp_dir().join("ini_rs_escaping_round_trips.ini");
+		Parser::new().escaping(true).write(&mut ini, path.to_str().unwrap()).unwrap();
+
+		let mut parser = Parser::new().escaping(true);
+		let map = parser.load(path.to_str().unwrap()).unwrap();
+		fs::remove_file(&path).ok();
+		assert_eq!(map["default"]["key"], Some(value.to_string()));
+	}
+
+	#[test]
+	fn custom_default_section_name_round_trips_through_read_and_write() {
+		let map = Parser::new().default_section_name("DEFAULT").read("key = value\n").unwrap();
+		assert_eq!(map["DEFAULT"]["key"], Some("value".to_string()));
+
+		let mut ini = Ini::new();
+		ini.get_mut_map().entry("DEFAULT".to_string()).or_default().insert("key".to_string(), Some("value".to_string()));
+		let path = std::env::temp_dir().join("ini_rs_custom_default_section_name_round_trips.ini");
+		Parser::new().default_section_name("DEFAULT").write(&mut ini, path.to_str().unwrap()).unwrap();
+		let contents = fs::read_to_string(&path).unwrap();
+		fs::remove_file(&path).ok();
+		assert!(!contents.contains('['), "a DEFAULT-only file shouldn't need a section header, got: {:?}", contents);
+
+		let map = Parser::new().default_section_name("DEFAULT").read(&contents).unwrap();
+		assert_eq!(map["DEFAULT"]["key"], Some("value".to_string()));
+	}
+
+	#[test]
+	fn write_leaves_the_callers_map_unescaped_afterwards() {
+		let mut ini = Ini::new();
+		ini.get_mut_map().entry("default".to_string()).or_default().insert("key".to_string(), Some("a=b".to_string()));
+		let path = std::env::temp_dir().join("ini_rs_write_leaves_map_unescaped.ini");
+		Parser::new().escaping(true).write(&mut ini, path.to_str().unwrap()).unwrap();
+		fs::remove_file(&path).ok();
+		assert_eq!(ini.get("default", "key"), Some("a=b".to_string()));
+	}
+}
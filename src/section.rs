@@ -0,0 +1,105 @@
+use configparser::ini::Ini;
+use std::collections::HashMap;
+
+/// A mutable handle onto a single section of an [`Ini`], returned by
+/// [`IniSectionExt::with_section`]. Chaining `.set()`/`.delete()` calls here is a much nicer
+/// write-path than reaching into `get_mut_map()` and juggling `String`/`Option<String>` pairs by hand.
+pub struct SectionHandle<'a> {
+	section: &'a mut HashMap<String, Option<String>>,
+}
+
+impl<'a> SectionHandle<'a> {
+	/// Inserts `key = value` into the section, overwriting any existing value. Like the rest of `Ini`,
+	/// the key is stored lowercased.
+	pub fn set(&mut self, key: &str, value: &str) -> &mut Self {
+		self.section.insert(key.to_lowercase(), Some(value.to_string()));
+		self
+	}
+
+	/// Removes `key` from the section, if present.
+	pub fn delete(&mut self, key: &str) -> &mut Self {
+		self.section.remove(&key.to_lowercase());
+		self
+	}
+}
+
+/// Adds a fluent section builder on top of `Ini`'s raw map access.
+pub trait IniSectionExt {
+	/// Returns a [`SectionHandle`] for `section`, or for `ini`'s configured default section
+	/// (see `Ini::set_default_section`) when `None`, creating it first if it doesn't exist yet.
+	///
+	/// ```ignore,rust
+	/// use configparser::ini::Ini;
+	/// use ini_rs::IniSectionExt;
+	///
+	/// let mut config = Ini::new();
+	/// config.with_section(Some("User")).set("given_name", "Tommy").set("family_name", "Green");
+	/// config.with_section(None).set("encoding", "utf-8");
+	/// ```
+	fn with_section(&mut self, section: Option<&str>) -> SectionHandle<'_>;
+}
+
+impl IniSectionExt for Ini {
+	fn with_section(&mut self, section: Option<&str>) -> SectionHandle<'_> {
+		let name = match section {
+			Some(name) => name.to_lowercase(),
+			None => self.defaults().default_section,
+		};
+		// `default_section` is stored and matched verbatim by `Ini` (unlike `[section]` headers,
+		// which it lowercases), so it's used as-is here too.
+		let section = self.get_mut_map().entry(name).or_default();
+		SectionHandle { section }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn with_section_creates_and_sets_values() {
+		let mut config = Ini::new();
+		config.with_section(Some("User")).set("given_name", "Tommy").set("family_name", "Green");
+		assert_eq!(config.get("user", "given_name"), Some("Tommy".to_string()));
+		assert_eq!(config.get("user", "family_name"), Some("Green".to_string()));
+	}
+
+	#[test]
+	fn with_section_none_writes_to_the_default_section() {
+		let mut config = Ini::new();
+		config.with_section(None).set("encoding", "utf-8");
+		assert_eq!(config.get("default", "encoding"), Some("utf-8".to_string()));
+	}
+
+	#[test]
+	fn with_section_none_respects_a_custom_default_section_name() {
+		let mut config = Ini::new();
+		config.set_default_section("TOPSECRET");
+		config.with_section(None).set("k", "v");
+		assert_eq!(config.get_map_ref()["TOPSECRET"]["k"], Some("v".to_string()));
+		assert_eq!(config.get_map_ref().get("default"), None);
+	}
+
+	#[test]
+	fn set_overwrites_an_existing_value() {
+		let mut config = Ini::new();
+		config.with_section(Some("a")).set("k", "v1");
+		config.with_section(Some("a")).set("k", "v2");
+		assert_eq!(config.get("a", "k"), Some("v2".to_string()));
+	}
+
+	#[test]
+	fn delete_removes_the_key() {
+		let mut config = Ini::new();
+		config.with_section(Some("a")).set("k", "v");
+		config.with_section(Some("a")).delete("k");
+		assert_eq!(config.get("a", "k"), None);
+	}
+
+	#[test]
+	fn keys_are_stored_lowercase() {
+		let mut config = Ini::new();
+		config.with_section(Some("User")).set("GivenName", "Tommy");
+		assert_eq!(config.get("user", "givenname"), Some("Tommy".to_string()));
+	}
+}